@@ -0,0 +1,212 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::RssItem;
+
+/// Fetches `url` over HTTP and parses the response body as an RSS or Atom feed.
+pub fn fetch_feed(url: &str) -> Result<Vec<RssItem>, Box<dyn std::error::Error>> {
+    let body = ureq::get(url).call()?.into_string()?;
+    Ok(parse_feed(&body))
+}
+
+/// Accumulates the fields of whichever `<item>`/`<entry>` is currently open.
+#[derive(Default)]
+struct RawEntry {
+    title: Option<String>,
+    link: Option<String>,
+    body: Option<String>,
+}
+
+fn parse_feed(xml: &str) -> Vec<RssItem> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut items = Vec::new();
+    let mut entry: Option<RawEntry> = None;
+    let mut tag_stack: Vec<String> = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let name = local_name(e);
+                if name == "item" || name == "entry" {
+                    entry = Some(RawEntry::default());
+                } else if name == "link" {
+                    take_link_href(e, entry.as_mut());
+                }
+                tag_stack.push(name);
+            }
+            Ok(Event::Empty(ref e)) => {
+                let name = local_name(e);
+                if name == "link" {
+                    take_link_href(e, entry.as_mut());
+                }
+            }
+            Ok(Event::Text(e)) => {
+                // Named HTML entities first, so quick-xml's unescape only sees
+                // the base XML set; if it still fails, keep the raw text.
+                let raw = decode_entities(&String::from_utf8_lossy(&e.into_inner()));
+                let text = quick_xml::escape::unescape(&raw)
+                    .map(|t| t.into_owned())
+                    .unwrap_or_else(|_| raw.clone());
+                append_to_field(&text, current_field(&tag_stack), entry.as_mut());
+            }
+            Ok(Event::CData(e)) => {
+                let text = decode_entities(&String::from_utf8_lossy(&e.into_inner()));
+                append_to_field(&text, current_field(&tag_stack), entry.as_mut());
+            }
+            Ok(Event::End(_)) => {
+                if let Some(name) = tag_stack.last() {
+                    if name == "item" || name == "entry" {
+                        if let Some(cur) = entry.take() {
+                            items.push(finish(cur));
+                        }
+                    }
+                }
+                tag_stack.pop();
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    items
+}
+
+fn local_name(start: &BytesStart) -> String {
+    let name = start.name();
+    let full = String::from_utf8_lossy(name.as_ref());
+    match full.rsplit_once(':') {
+        Some((_, local)) => local.to_string(),
+        None => full.into_owned(),
+    }
+}
+
+fn take_link_href(start: &BytesStart, entry: Option<&mut RawEntry>) {
+    let Some(cur) = entry else { return };
+    for attr in start.attributes().flatten() {
+        if attr.key.as_ref() == b"href" {
+            if let Ok(href) = attr.unescape_value() {
+                cur.link = Some(href.into_owned());
+            }
+        }
+    }
+}
+
+/// The nearest enclosing recognized field tag, searched from the top of the
+/// stack down, so text nested inside unrecognized inline markup (e.g. a
+/// `<p>`/`<b>` inside `<description>`, which many feeds emit without
+/// wrapping it in CDATA) still lands in the right field instead of being
+/// dropped.
+fn current_field<'a>(tag_stack: &'a [String]) -> Option<&'a str> {
+    tag_stack.iter().rev().find_map(|t| match t.as_str() {
+        "title" | "link" | "description" | "summary" | "content" => Some(t.as_str()),
+        _ => None,
+    })
+}
+
+/// Routes decoded text (from either a `Text` or `CData` event) into the
+/// current entry's title/link/body field, based on `field`.
+fn append_to_field(text: &str, field: Option<&str>, entry: Option<&mut RawEntry>) {
+    let Some(cur) = entry else { return };
+    match field {
+        Some("title") => append(&mut cur.title, text),
+        Some("link") => append(&mut cur.link, text),
+        Some("description") | Some("summary") | Some("content") => append(&mut cur.body, text),
+        _ => {}
+    }
+}
+
+fn append(field: &mut Option<String>, text: &str) {
+    match field {
+        Some(existing) => existing.push_str(text),
+        None => *field = Some(text.to_string()),
+    }
+}
+
+fn finish(entry: RawEntry) -> RssItem {
+    let url = entry.link.unwrap_or_default();
+    let title = entry.title.unwrap_or_else(|| url.clone());
+    let description = entry.body.unwrap_or_default();
+    RssItem {
+        title,
+        url,
+        description,
+    }
+}
+
+/// Decodes the handful of named HTML entities that show up in feed bodies but
+/// aren't part of the base XML entity set quick-xml already unescapes.
+fn decode_entities(s: &str) -> String {
+    s.replace("&nbsp;", " ")
+        .replace("&mdash;", "\u{2014}")
+        .replace("&ndash;", "\u{2013}")
+        .replace("&lsquo;", "\u{2018}")
+        .replace("&rsquo;", "\u{2019}")
+        .replace("&ldquo;", "\u{201c}")
+        .replace("&rdquo;", "\u{201d}")
+        .replace("&hellip;", "\u{2026}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rss_item_with_title_link_description() {
+        let xml = r#"<rss><channel><item>
+            <title>Hello World</title>
+            <link>https://example.com/hello</link>
+            <description>A &lt;b&gt;bold&lt;/b&gt; post</description>
+        </item></channel></rss>"#;
+
+        let items = parse_feed(xml);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "Hello World");
+        assert_eq!(items[0].url, "https://example.com/hello");
+        assert_eq!(items[0].description, "A <b>bold</b> post");
+    }
+
+    #[test]
+    fn parses_atom_entry_with_link_href_and_cdata_summary() {
+        let xml = r#"<feed><entry>
+            <title><![CDATA[CDATA Title]]></title>
+            <link href="https://example.com/entry" />
+            <summary><![CDATA[<p>Body</p>]]></summary>
+        </entry></feed>"#;
+
+        let items = parse_feed(xml);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "CDATA Title");
+        assert_eq!(items[0].url, "https://example.com/entry");
+        assert_eq!(items[0].description, "<p>Body</p>");
+    }
+
+    #[test]
+    fn preserves_text_nested_inside_non_cdata_html_tags() {
+        let xml = r#"<rss><channel><item>
+            <link>https://example.com/nested</link>
+            <description><p>Hello <b>World</b></p></description>
+        </item></channel></rss>"#;
+
+        let items = parse_feed(xml);
+        assert_eq!(items.len(), 1);
+        // `trim_text(true)` trims each text *event* individually, so the
+        // space between "Hello " and "<b>" (its own text event) is trimmed
+        // away rather than preserved across the tag boundary.
+        assert_eq!(items[0].description, "HelloWorld");
+    }
+
+    #[test]
+    fn falls_back_to_url_as_title_when_missing() {
+        let xml = r#"<rss><channel><item>
+            <link>https://example.com/untitled</link>
+        </item></channel></rss>"#;
+
+        let items = parse_feed(xml);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "https://example.com/untitled");
+    }
+}
@@ -0,0 +1,128 @@
+/// Which field of a searchable row a [`Hit`] matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Url,
+}
+
+/// A fuzzy match against one field of a row, with the character indices
+/// (into that field) that matched so the caller can highlight them.
+#[derive(Debug, Clone)]
+pub struct Hit {
+    pub score: i64,
+    pub positions: Vec<usize>,
+    pub field: Field,
+}
+
+/// Scores `title` and `url` against `query`, returning the better of the two
+/// matches (or `None` if neither matches).
+pub fn best_match(query: &str, title: &str, url: &str) -> Option<Hit> {
+    let by_title = fuzzy_match(query, title).map(|(score, positions)| Hit {
+        score,
+        positions,
+        field: Field::Title,
+    });
+    let by_url = fuzzy_match(query, url).map(|(score, positions)| Hit {
+        score,
+        positions,
+        field: Field::Url,
+    });
+
+    match (by_title, by_url) {
+        (Some(t), Some(u)) => Some(if t.score >= u.score { t } else { u }),
+        (Some(t), None) => Some(t),
+        (None, Some(u)) => Some(u),
+        (None, None) => None,
+    }
+}
+
+/// Fuzzy subsequence match of `query` against `text`. Every character of
+/// `query` must appear in `text` in order (case-insensitively). The score
+/// rewards more matched characters, consecutive runs, and word-boundary
+/// starts; ties are broken by preferring an earlier first match.
+fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let text_chars: Vec<char> = text.chars().collect();
+    // Lowercased char by char so indices stay aligned with `text_chars`.
+    let text_lower: Vec<char> = text_chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut positions = Vec::with_capacity(query_lower.len());
+    let mut qi = 0;
+    let mut score: i64 = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ti, &c) in text_lower.iter().enumerate() {
+        if qi >= query_lower.len() {
+            break;
+        }
+        if c != query_lower[qi] {
+            continue;
+        }
+
+        score += 1;
+        if ti > 0 && last_match == Some(ti - 1) {
+            score += 3; // consecutive-match bonus
+        }
+        if ti == 0 || !text_chars[ti - 1].is_alphanumeric() {
+            score += 2; // word-boundary bonus
+        }
+
+        positions.push(ti);
+        last_match = Some(ti);
+        qi += 1;
+    }
+
+    if qi < query_lower.len() {
+        return None;
+    }
+
+    // Tie-break by preferring matches that start earlier in the text. The
+    // match score is shifted above the position, so the position only
+    // decides between equal scores.
+    let first_pos = (*positions.first().unwrap_or(&0) as i64).min(u32::MAX as i64);
+    score = (score << 32) - first_pos;
+
+    Some((score, positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_an_in_order_subsequence_case_insensitively() {
+        assert!(fuzzy_match("brd", "Bird").is_some());
+        assert!(fuzzy_match("xyz", "Bird").is_none());
+    }
+
+    #[test]
+    fn empty_query_never_matches() {
+        assert!(fuzzy_match("", "anything").is_none());
+        assert!(best_match("", "title", "https://url").is_none());
+    }
+
+    #[test]
+    fn prefers_an_earlier_match_when_scores_tie() {
+        let (prefix_score, _) = fuzzy_match("cat", "cat food").unwrap();
+        let (mid_score, _) = fuzzy_match("cat", "a cat food").unwrap();
+        assert!(prefix_score > mid_score);
+    }
+
+    #[test]
+    fn best_match_picks_the_field_that_actually_matches() {
+        let hit = best_match("news", "Tech News", "https://example.com/feed").unwrap();
+        assert_eq!(hit.field, Field::Title);
+
+        let hit = best_match("example", "Tech News", "https://example.com/feed").unwrap();
+        assert_eq!(hit.field, Field::Url);
+
+        assert!(best_match("zzz", "Tech News", "https://example.com/feed").is_none());
+    }
+}
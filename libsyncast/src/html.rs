@@ -0,0 +1,185 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Span, Spans};
+
+/// Converts a subset of HTML into styled terminal spans plus the list of
+/// links it found, stripping any tag it doesn't recognize while still
+/// decoding entities. Meant for rendering feed article bodies, not general
+/// HTML.
+pub fn render_html(input: &str) -> (Vec<Spans<'static>>, Vec<String>) {
+    let mut lines: Vec<Vec<Span<'static>>> = vec![Vec::new()];
+    let mut links = Vec::new();
+    let mut style_stack = vec![Style::default()];
+
+    let mut i = 0;
+    while i < input.len() {
+        if input.as_bytes()[i] == b'<' {
+            if let Some(end) = input[i..].find('>') {
+                let tag = parse_tag(&input[i + 1..i + end]);
+                apply_tag(&tag, &mut style_stack, &mut lines, &mut links);
+                i += end + 1;
+                continue;
+            }
+            // A lone `<` with no matching `>` (truncated markup, or just
+            // literal text like "Profit < 10%") — treat it as text so we
+            // don't re-find the same `<` forever.
+            push_text(&mut lines, "<".to_string(), *style_stack.last().unwrap());
+            i += 1;
+            continue;
+        }
+        let next_lt = input[i..].find('<').map(|p| i + p).unwrap_or(input.len());
+        let text = decode_entities(&input[i..next_lt]);
+        if !text.is_empty() {
+            push_text(&mut lines, text, *style_stack.last().unwrap());
+        }
+        i = next_lt;
+    }
+
+    (lines.into_iter().map(Spans::from).collect(), links)
+}
+
+struct Tag {
+    name: String,
+    closing: bool,
+    attrs: String,
+}
+
+fn parse_tag(raw: &str) -> Tag {
+    let mut raw = raw.trim();
+    let closing = raw.starts_with('/');
+    if closing {
+        raw = &raw[1..];
+    }
+    let raw = raw.strip_suffix('/').unwrap_or(raw).trim_end();
+
+    let (name, attrs) = match raw.find(char::is_whitespace) {
+        Some(idx) => (&raw[..idx], raw[idx..].trim()),
+        None => (raw, ""),
+    };
+
+    Tag {
+        name: name.to_ascii_lowercase(),
+        closing,
+        attrs: attrs.to_string(),
+    }
+}
+
+fn apply_tag(
+    tag: &Tag,
+    style_stack: &mut Vec<Style>,
+    lines: &mut Vec<Vec<Span<'static>>>,
+    links: &mut Vec<String>,
+) {
+    match tag.name.as_str() {
+        "b" | "strong" => toggle_modifier(tag, style_stack, Modifier::BOLD),
+        "i" | "em" => toggle_modifier(tag, style_stack, Modifier::ITALIC),
+        "a" => {
+            if tag.closing {
+                pop_style(style_stack);
+            } else {
+                if let Some(href) = extract_attr(&tag.attrs, "href") {
+                    links.push(href);
+                }
+                push_style(style_stack, Modifier::UNDERLINED);
+            }
+        }
+        "p" | "br" => new_line(lines),
+        "li" => {
+            if !tag.closing {
+                push_text(lines, "\u{2022} ".to_string(), *style_stack.last().unwrap());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn toggle_modifier(tag: &Tag, style_stack: &mut Vec<Style>, modifier: Modifier) {
+    if tag.closing {
+        pop_style(style_stack);
+    } else {
+        push_style(style_stack, modifier);
+    }
+}
+
+fn push_style(style_stack: &mut Vec<Style>, modifier: Modifier) {
+    let style = style_stack.last().copied().unwrap_or_default();
+    style_stack.push(style.add_modifier(modifier));
+}
+
+fn pop_style(style_stack: &mut Vec<Style>) {
+    if style_stack.len() > 1 {
+        style_stack.pop();
+    }
+}
+
+fn new_line(lines: &mut Vec<Vec<Span<'static>>>) {
+    // Avoid stacking up multiple blank lines for back-to-back breaks.
+    if lines.last().map(Vec::is_empty) != Some(true) {
+        lines.push(Vec::new());
+    }
+}
+
+fn extract_attr(attrs: &str, key: &str) -> Option<String> {
+    for part in attrs.split_whitespace() {
+        let (name, value) = part.split_once('=')?;
+        if name.eq_ignore_ascii_case(key) {
+            return Some(value.trim_matches(['"', '\'']).to_string());
+        }
+    }
+    None
+}
+
+fn push_text(lines: &mut Vec<Vec<Span<'static>>>, text: String, style: Style) {
+    if let Some(last) = lines.last_mut() {
+        last.push(Span::styled(text, style));
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&mdash;", "\u{2014}")
+        .replace("&ndash;", "\u{2013}")
+        .replace("&hellip;", "\u{2026}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flatten(spans: &[Spans<'static>]) -> String {
+        spans
+            .iter()
+            .flat_map(|line| line.0.iter())
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn bold_and_italic_tags_render_their_text() {
+        let (spans, _links) = render_html("<b>bold</b> and <i>italic</i>");
+        assert_eq!(flatten(&spans), "bold and italic");
+    }
+
+    #[test]
+    fn link_href_is_collected() {
+        let (_spans, links) = render_html(r#"<a href="https://example.com">click</a>"#);
+        assert_eq!(links, vec!["https://example.com".to_string()]);
+    }
+
+    #[test]
+    fn unterminated_angle_bracket_is_treated_as_literal_text() {
+        let (spans, _links) = render_html("Profit < 10% and rising");
+        assert_eq!(flatten(&spans), "Profit < 10% and rising");
+    }
+
+    #[test]
+    fn entities_are_decoded() {
+        let (spans, _links) = render_html("Tom &amp; Jerry");
+        assert_eq!(flatten(&spans), "Tom & Jerry");
+    }
+}
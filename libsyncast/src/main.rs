@@ -1,16 +1,24 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::{BufRead, BufReader, Write},
+    collections::HashSet,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
 };
 
+use ratatui::widgets::ListState;
+
+mod feed;
+mod fuzzy;
+mod html;
+mod store;
+mod theme;
 mod ui;
+use store::{JsonStore, Store, TextStore};
 use ui::UI;
 
-const FEED_CONF: &str = "feeds.txt";
-const HISTORY_FILE: &str = "history.txt";
-const FAVORITES_FILE: &str = "favorites.txt";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct RssItem {
     title: String,
     url: String,
@@ -21,6 +29,16 @@ struct RssItem {
 pub struct Folder {
     name: String,
     feeds: Vec<String>,
+    items: Vec<RssItem>,
+    refreshing: bool,
+    last_fetch: Instant,
+}
+
+/// A batch of freshly fetched items for one folder, sent back from a
+/// background fetch thread.
+struct FeedUpdate {
+    folder: usize,
+    items: Vec<RssItem>,
 }
 
 #[derive(Debug)]
@@ -35,28 +53,66 @@ pub struct FavoriteItem {
     url: String,
 }
 
-#[derive(Debug)]
 pub struct AppState {
     pub folders: Vec<Folder>,
-    pub selected_folder: usize,
+    pub folder_sel: ListState,
     pub history: Vec<HistoryItem>,
+    pub history_sel: ListState,
     pub favorites: Vec<FavoriteItem>,
+    pub favorite_sel: ListState,
     pub show_history: bool,
     pub show_favorites: bool,
-    pub selected_favorite: usize,
+    pub folder_drilldown: bool,
+    pub article_sel: ListState,
+    pub force_refresh: bool,
+    pub search_active: bool,
+    pub pending_favorite: bool,
+    pub pending_history: bool,
+    pub query: String,
+    store: Box<dyn Store>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // `LIBSYNCAST_STORE=json` switches to the structured JSON backend;
+    // anything else (including unset) keeps the flat-file default.
+    let store: Box<dyn Store> = match std::env::var("LIBSYNCAST_STORE").as_deref() {
+        Ok("json") => Box::new(JsonStore::new()),
+        _ => Box::new(TextStore::new()),
+    };
+
+    let mut folder_sel = ListState::default();
+    folder_sel.select(Some(0));
+    let mut history_sel = ListState::default();
+    history_sel.select(Some(0));
+    let mut favorite_sel = ListState::default();
+    favorite_sel.select(Some(0));
+    let mut article_sel = ListState::default();
+    article_sel.select(Some(0));
+
     let mut app_state = AppState {
-        folders: load_folders_conf()?,
-        selected_folder: 0,
-        history: load_history()?,
-        favorites: load_favorites()?,
+        folders: store.load_folders()?,
+        folder_sel,
+        history: store.load_history()?,
+        history_sel,
+        favorites: store.load_favorites()?,
+        favorite_sel,
         show_history: false,
         show_favorites: false,
-        selected_favorite: 0,
+        folder_drilldown: false,
+        article_sel,
+        force_refresh: false,
+        search_active: false,
+        pending_favorite: false,
+        pending_history: false,
+        query: String::new(),
+        store,
     };
 
+    let (tx, rx): (Sender<FeedUpdate>, Receiver<FeedUpdate>) = mpsc::channel();
+    for idx in 0..app_state.folders.len() {
+        spawn_refresh(idx, &mut app_state.folders, &tx);
+    }
+
     // Initialize UI
     let mut ui = UI::new()?;
 
@@ -71,112 +127,89 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         // Handle specific application actions
-        if app_state.show_history && ui.handle_favorite_action(&mut app_state)? {
+        if ui.handle_favorite_action(&mut app_state)? {
             // Handle adding an item to favorites
-            if let Some(history_item) = app_state.history.get(app_state.selected_folder) {
-                save_to_favorites(&history_item.title, &history_item.url)?;
-                app_state.favorites.push(FavoriteItem {
-                    title: history_item.title.clone(),
-                    url: history_item.url.clone(),
-                });
+            if let Some(history_item) = app_state.history.get(app_state.history_sel.selected().unwrap_or(0)) {
+                let title = history_item.title.clone();
+                let url = history_item.url.clone();
+                app_state.store.add_favorite(&title, &url)?;
+                app_state.favorites.push(FavoriteItem { title, url });
             }
         }
-    }
-
-    // Clean up UI
-    ui.cleanup()?;
-    Ok(())
-}
 
-fn load_folders_conf() -> Result<Vec<Folder>, Box<dyn std::error::Error>> {
-    let file = match File::open(FEED_CONF) {
-        Ok(f) => f,
-        Err(_) => {
-            let mut f = File::create(FEED_CONF)?;
-            f.write_all(b"default_folder:\nhttps://example.com/rss\n")?;
-            File::open(FEED_CONF)?
+        if ui.handle_history_action(&mut app_state)? {
+            // Handle marking the selected article as read
+            let folder = app_state.folders.get(app_state.folder_sel.selected().unwrap_or(0));
+            let item = folder.and_then(|f| f.items.get(app_state.article_sel.selected().unwrap_or(0)));
+            if let Some(item) = item {
+                let title = item.title.clone();
+                let url = item.url.clone();
+                app_state.store.add_history(&title, &url)?;
+                app_state.history.push(HistoryItem { title, url });
+            }
         }
-    };
 
-    let reader = BufReader::new(file);
-    let mut folders = Vec::new();
-    let mut current_folder: Option<Folder> = None;
+        // Merge any fetch results that have come back since the last frame.
+        while let Ok(update) = rx.try_recv() {
+            if let Some(folder) = app_state.folders.get_mut(update.folder) {
+                if urls_changed(&folder.items, &update.items) {
+                    folder.items = update.items;
+                }
+                folder.refreshing = false;
+            }
+        }
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.ends_with(':') {
-            if let Some(folder) = current_folder.take() {
-                folders.push(folder);
+        if app_state.force_refresh {
+            app_state.force_refresh = false;
+            let idx = app_state.folder_sel.selected().unwrap_or(0);
+            let already_refreshing = app_state.folders.get(idx).map(|f| f.refreshing).unwrap_or(false);
+            if !already_refreshing {
+                spawn_refresh(idx, &mut app_state.folders, &tx);
             }
-            current_folder = Some(Folder {
-                name: line.trim_end_matches(':').to_string(),
-                feeds: Vec::new(),
-            });
-        } else if let Some(folder) = &mut current_folder {
-            if !line.trim().is_empty() {
-                folder.feeds.push(line);
+        }
+
+        for idx in 0..app_state.folders.len() {
+            let due = !app_state.folders[idx].refreshing
+                && app_state.folders[idx].last_fetch.elapsed() >= REFRESH_INTERVAL;
+            if due {
+                spawn_refresh(idx, &mut app_state.folders, &tx);
             }
         }
     }
-    if let Some(folder) = current_folder {
-        folders.push(folder);
-    }
 
-    Ok(folders)
+    // Clean up UI
+    ui.cleanup()?;
+    Ok(())
 }
 
-fn load_history() -> Result<Vec<HistoryItem>, Box<dyn std::error::Error>> {
-    let file = File::open(HISTORY_FILE).unwrap_or_else(|_| File::create(HISTORY_FILE).unwrap());
-    let reader = BufReader::new(file);
-    Ok(reader
-        .lines()
-        .filter_map(|line| {
-            if let Ok(line) = line {
-                let parts: Vec<&str> = line.splitn(2, ' ').collect();
-                if parts.len() == 2 {
-                    Some(HistoryItem {
-                        title: parts[0].to_string(),
-                        url: parts[1].to_string(),
-                    })
-                } else {
-                    None
-                }
-            } else {
-                None
+/// Kicks off a background fetch of every feed in `folders[idx]`, marking the
+/// folder as refreshing until the result arrives on `tx`.
+fn spawn_refresh(idx: usize, folders: &mut [Folder], tx: &Sender<FeedUpdate>) {
+    let Some(folder) = folders.get_mut(idx) else {
+        return;
+    };
+    folder.refreshing = true;
+    folder.last_fetch = Instant::now();
+
+    let feeds = folder.feeds.clone();
+    let tx = tx.clone();
+    thread::spawn(move || {
+        let mut items = Vec::new();
+        for url in &feeds {
+            match feed::fetch_feed(url) {
+                Ok(mut fetched) => items.append(&mut fetched),
+                Err(err) => eprintln!("failed to fetch {}: {}", url, err),
             }
-        })
-        .collect())
+        }
+        let _ = tx.send(FeedUpdate { folder: idx, items });
+    });
 }
 
-fn load_favorites() -> Result<Vec<FavoriteItem>, Box<dyn std::error::Error>> {
-    let file = File::open(FAVORITES_FILE).unwrap_or_else(|_| File::create(FAVORITES_FILE).unwrap());
-    let reader = BufReader::new(file);
-    Ok(reader
-        .lines()
-        .filter_map(|line| {
-            if let Ok(line) = line {
-                let parts: Vec<&str> = line.splitn(2, ' ').collect();
-                if parts.len() == 2 {
-                    Some(FavoriteItem {
-                        title: parts[0].to_string(),
-                        url: parts[1].to_string(),
-                    })
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        })
-        .collect())
+/// Whether the set of article URLs differs, used to keep a folder's current
+/// items in place when a refresh came back with the same set of URLs.
+fn urls_changed(old: &[RssItem], new: &[RssItem]) -> bool {
+    let old_urls: HashSet<&str> = old.iter().map(|item| item.url.as_str()).collect();
+    let new_urls: HashSet<&str> = new.iter().map(|item| item.url.as_str()).collect();
+    old_urls != new_urls
 }
 
-fn save_to_favorites(title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(FAVORITES_FILE)?;
-    
-    writeln!(file, "{} {}", title, url)?;
-    Ok(())
-}
@@ -0,0 +1,436 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{FavoriteItem, Folder, HistoryItem};
+
+/// Persists folders, history, and favorites. `AppState` depends only on this
+/// trait, not on any particular on-disk format.
+pub trait Store {
+    fn load_folders(&self) -> Result<Vec<Folder>, Box<dyn std::error::Error>>;
+    fn load_history(&self) -> Result<Vec<HistoryItem>, Box<dyn std::error::Error>>;
+    fn load_favorites(&self) -> Result<Vec<FavoriteItem>, Box<dyn std::error::Error>>;
+    fn add_favorite(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>>;
+    fn add_history(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+const FEED_CONF: &str = "feeds.txt";
+const HISTORY_FILE: &str = "history.txt";
+const FAVORITES_FILE: &str = "favorites.txt";
+
+/// The original flat-file backend: `name:`-prefixed sections of feed URLs in
+/// `feeds.txt`, and `title url` lines in `history.txt` / `favorites.txt`.
+///
+/// Paths are fields rather than the bare consts directly, so tests can point
+/// a `TextStore` at scratch files instead of the real working directory.
+pub struct TextStore {
+    feeds_path: String,
+    history_path: String,
+    favorites_path: String,
+}
+
+impl TextStore {
+    pub fn new() -> Self {
+        Self {
+            feeds_path: FEED_CONF.to_string(),
+            history_path: HISTORY_FILE.to_string(),
+            favorites_path: FAVORITES_FILE.to_string(),
+        }
+    }
+}
+
+impl Store for TextStore {
+    fn load_folders(&self) -> Result<Vec<Folder>, Box<dyn std::error::Error>> {
+        let file = match File::open(&self.feeds_path) {
+            Ok(f) => f,
+            Err(_) => {
+                let mut f = File::create(&self.feeds_path)?;
+                f.write_all(b"default_folder:\nhttps://example.com/rss\n")?;
+                File::open(&self.feeds_path)?
+            }
+        };
+
+        let reader = BufReader::new(file);
+        let mut folders = Vec::new();
+        let mut current_folder: Option<Folder> = None;
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.ends_with(':') {
+                if let Some(folder) = current_folder.take() {
+                    folders.push(folder);
+                }
+                current_folder = Some(Folder {
+                    name: line.trim_end_matches(':').to_string(),
+                    feeds: Vec::new(),
+                    items: Vec::new(),
+                    refreshing: false,
+                    last_fetch: Instant::now(),
+                });
+            } else if let Some(folder) = &mut current_folder {
+                if !line.trim().is_empty() {
+                    folder.feeds.push(line);
+                }
+            }
+        }
+        if let Some(folder) = current_folder {
+            folders.push(folder);
+        }
+
+        Ok(folders)
+    }
+
+    fn load_history(&self) -> Result<Vec<HistoryItem>, Box<dyn std::error::Error>> {
+        read_title_url_file(&self.history_path, |title, url| HistoryItem { title, url })
+    }
+
+    fn load_favorites(&self) -> Result<Vec<FavoriteItem>, Box<dyn std::error::Error>> {
+        read_title_url_file(&self.favorites_path, |title, url| FavoriteItem { title, url })
+    }
+
+    fn add_favorite(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        append_title_url_line(&self.favorites_path, title, url)
+    }
+
+    fn add_history(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        append_title_url_line(&self.history_path, title, url)
+    }
+}
+
+/// Reads `title url` lines, splitting on the *last* space so a title
+/// containing spaces doesn't get truncated (the URL itself never does).
+fn read_title_url_file<T>(
+    path: &str,
+    build: impl Fn(String, String) -> T,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    let file = File::open(path).unwrap_or_else(|_| File::create(path).unwrap());
+    let reader = BufReader::new(file);
+    Ok(reader
+        .lines()
+        .filter_map(|line| {
+            let line = line.ok()?;
+            let (title, url) = line.rsplit_once(' ')?;
+            Some(build(title.to_string(), url.to_string()))
+        })
+        .collect())
+}
+
+fn append_title_url_line(path: &str, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{} {}", title, url)?;
+    Ok(())
+}
+
+const FOLDERS_JSON: &str = "feeds.json";
+const HISTORY_JSON: &str = "history.json";
+const FAVORITES_JSON: &str = "favorites.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FolderRecord {
+    name: String,
+    feeds: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HistoryRecord {
+    title: String,
+    url: String,
+    #[serde(default)]
+    read: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FavoriteRecord {
+    title: String,
+    url: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// A structured JSON backend. Titles and URLs are stored as whole fields
+/// rather than split out of a single line, and each record has room for
+/// fields (read state, tags, fetch timestamps) that the flat-file format
+/// can't add without breaking its parser.
+///
+/// Paths are fields rather than the bare consts directly, so tests can point
+/// a `JsonStore` at scratch files instead of the real working directory.
+pub struct JsonStore {
+    folders_path: String,
+    history_path: String,
+    favorites_path: String,
+}
+
+impl JsonStore {
+    pub fn new() -> Self {
+        Self {
+            folders_path: FOLDERS_JSON.to_string(),
+            history_path: HISTORY_JSON.to_string(),
+            favorites_path: FAVORITES_JSON.to_string(),
+        }
+    }
+}
+
+impl Store for JsonStore {
+    fn load_folders(&self) -> Result<Vec<Folder>, Box<dyn std::error::Error>> {
+        let records: Vec<FolderRecord> = read_json(&self.folders_path)?;
+        Ok(records
+            .into_iter()
+            .map(|r| Folder {
+                name: r.name,
+                feeds: r.feeds,
+                items: Vec::new(),
+                refreshing: false,
+                last_fetch: Instant::now(),
+            })
+            .collect())
+    }
+
+    fn load_history(&self) -> Result<Vec<HistoryItem>, Box<dyn std::error::Error>> {
+        let records: Vec<HistoryRecord> = read_json(&self.history_path)?;
+        Ok(records
+            .into_iter()
+            .map(|r| HistoryItem { title: r.title, url: r.url })
+            .collect())
+    }
+
+    fn load_favorites(&self) -> Result<Vec<FavoriteItem>, Box<dyn std::error::Error>> {
+        let records: Vec<FavoriteRecord> = read_json(&self.favorites_path)?;
+        Ok(records
+            .into_iter()
+            .map(|r| FavoriteItem { title: r.title, url: r.url })
+            .collect())
+    }
+
+    fn add_favorite(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut records: Vec<FavoriteRecord> = read_json(&self.favorites_path)?;
+        records.push(FavoriteRecord {
+            title: title.to_string(),
+            url: url.to_string(),
+            tags: Vec::new(),
+        });
+        write_json(&self.favorites_path, &records)
+    }
+
+    fn add_history(&self, title: &str, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut records: Vec<HistoryRecord> = read_json(&self.history_path)?;
+        records.push(HistoryRecord {
+            title: title.to_string(),
+            url: url.to_string(),
+            read: true,
+        });
+        write_json(&self.history_path, &records)
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &str) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(serde_json::from_str(&contents)?),
+        Err(_) => Ok(Vec::new()),
+    }
+}
+
+fn write_json<T: Serialize>(path: &str, records: &[T]) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(path, serde_json::to_string_pretty(records)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch file path unique to this test process and name, so
+    /// concurrently-running tests never collide on the same file.
+    fn temp_path(name: &str) -> String {
+        let mut path = std::env::temp_dir();
+        path.push(format!("libsyncast_test_{}_{}", std::process::id(), name));
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn title_url_lines_round_trip_through_append_and_read() {
+        let path = temp_path("history.txt");
+        let _ = fs::remove_file(&path);
+
+        append_title_url_line(&path, "Breaking: A, B & C", "https://example.com/a").unwrap();
+        append_title_url_line(&path, "Second Item", "https://example.com/b").unwrap();
+
+        let items: Vec<(String, String)> =
+            read_title_url_file(&path, |title, url| (title, url)).unwrap();
+
+        assert_eq!(
+            items,
+            vec![
+                ("Breaking: A, B & C".to_string(), "https://example.com/a".to_string()),
+                ("Second Item".to_string(), "https://example.com/b".to_string()),
+            ]
+        );
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn json_records_round_trip_through_write_and_read() {
+        let path = temp_path("favorites.json");
+        let _ = fs::remove_file(&path);
+
+        let records = vec![FavoriteRecord {
+            title: "A".to_string(),
+            url: "https://a.example".to_string(),
+            tags: vec!["x".to_string()],
+        }];
+        write_json(&path, &records).unwrap();
+
+        let loaded: Vec<FavoriteRecord> = read_json(&path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].title, "A");
+        assert_eq!(loaded[0].url, "https://a.example");
+        assert_eq!(loaded[0].tags, vec!["x".to_string()]);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn read_json_returns_empty_when_file_is_missing() {
+        let path = temp_path("missing.json");
+        let _ = fs::remove_file(&path);
+
+        let loaded: Vec<FavoriteRecord> = read_json(&path).unwrap();
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn text_store_load_folders_groups_feeds_under_name_header() {
+        let feeds_path = temp_path("feeds.txt");
+        let _ = fs::remove_file(&feeds_path);
+        fs::write(
+            &feeds_path,
+            "Tech:\nhttps://a.example/rss\nhttps://b.example/rss\nNews:\nhttps://c.example/rss\n",
+        )
+        .unwrap();
+
+        let store = TextStore {
+            feeds_path: feeds_path.clone(),
+            history_path: temp_path("unused_history.txt"),
+            favorites_path: temp_path("unused_favorites.txt"),
+        };
+
+        let folders = store.load_folders().unwrap();
+        assert_eq!(folders.len(), 2);
+        assert_eq!(folders[0].name, "Tech");
+        assert_eq!(
+            folders[0].feeds,
+            vec!["https://a.example/rss".to_string(), "https://b.example/rss".to_string()]
+        );
+        assert_eq!(folders[1].name, "News");
+        assert_eq!(folders[1].feeds, vec!["https://c.example/rss".to_string()]);
+
+        let _ = fs::remove_file(&feeds_path);
+    }
+
+    #[test]
+    fn text_store_load_folders_creates_a_default_when_missing() {
+        let feeds_path = temp_path("missing_feeds.txt");
+        let _ = fs::remove_file(&feeds_path);
+
+        let store = TextStore {
+            feeds_path: feeds_path.clone(),
+            history_path: temp_path("unused_history2.txt"),
+            favorites_path: temp_path("unused_favorites2.txt"),
+        };
+
+        let folders = store.load_folders().unwrap();
+        assert_eq!(folders.len(), 1);
+        assert_eq!(folders[0].name, "default_folder");
+
+        let _ = fs::remove_file(&feeds_path);
+    }
+
+    #[test]
+    fn text_store_add_history_is_picked_up_by_load_history() {
+        let history_path = temp_path("history_roundtrip.txt");
+        let _ = fs::remove_file(&history_path);
+
+        let store = TextStore {
+            feeds_path: temp_path("unused_feeds.txt"),
+            history_path: history_path.clone(),
+            favorites_path: temp_path("unused_favorites3.txt"),
+        };
+
+        store.add_history("Some Article", "https://example.com/article").unwrap();
+        let history = store.load_history().unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].title, "Some Article");
+        assert_eq!(history[0].url, "https://example.com/article");
+
+        let _ = fs::remove_file(&history_path);
+    }
+
+    #[test]
+    fn text_store_add_favorite_is_picked_up_by_load_favorites() {
+        let favorites_path = temp_path("favorites_roundtrip.txt");
+        let _ = fs::remove_file(&favorites_path);
+
+        let store = TextStore {
+            feeds_path: temp_path("unused_feeds2.txt"),
+            history_path: temp_path("unused_history3.txt"),
+            favorites_path: favorites_path.clone(),
+        };
+
+        store.add_favorite("Starred Article", "https://example.com/starred").unwrap();
+        let favorites = store.load_favorites().unwrap();
+        assert_eq!(favorites.len(), 1);
+        assert_eq!(favorites[0].title, "Starred Article");
+        assert_eq!(favorites[0].url, "https://example.com/starred");
+
+        let _ = fs::remove_file(&favorites_path);
+    }
+
+    #[test]
+    fn json_store_load_folders_reads_records() {
+        let folders_path = temp_path("folders_roundtrip.json");
+        let _ = fs::remove_file(&folders_path);
+        write_json(
+            &folders_path,
+            &[FolderRecord {
+                name: "Tech".to_string(),
+                feeds: vec!["https://a.example/rss".to_string()],
+            }],
+        )
+        .unwrap();
+
+        let store = JsonStore {
+            folders_path: folders_path.clone(),
+            history_path: temp_path("unused_history.json"),
+            favorites_path: temp_path("unused_favorites.json"),
+        };
+
+        let folders = store.load_folders().unwrap();
+        assert_eq!(folders.len(), 1);
+        assert_eq!(folders[0].name, "Tech");
+        assert_eq!(folders[0].feeds, vec!["https://a.example/rss".to_string()]);
+
+        let _ = fs::remove_file(&folders_path);
+    }
+
+    #[test]
+    fn json_store_add_history_is_picked_up_by_load_history() {
+        let history_path = temp_path("history_roundtrip.json");
+        let _ = fs::remove_file(&history_path);
+
+        let store = JsonStore {
+            folders_path: temp_path("unused_folders.json"),
+            history_path: history_path.clone(),
+            favorites_path: temp_path("unused_favorites2.json"),
+        };
+
+        store.add_history("Some Article", "https://example.com/article").unwrap();
+        let history = store.load_history().unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].title, "Some Article");
+        assert_eq!(history[0].url, "https://example.com/article");
+
+        let _ = fs::remove_file(&history_path);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fs;
+
+use ratatui::style::Color;
+
+const THEME_FILE: &str = "theme.toml";
+
+/// Colors used throughout the UI, loadable from `theme.toml` so users can
+/// recolor the app without recompiling it.
+pub struct Theme {
+    pub active_tab: Color,
+    pub inactive_tab: Color,
+    pub selected_item: Color,
+    pub border: Color,
+    pub title: Color,
+    pub text: Color,
+    pub highlight: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            active_tab: Color::Rgb(252, 152, 103), // Coral accent color
+            inactive_tab: Color::DarkGray,
+            selected_item: Color::Rgb(252, 152, 103), // Coral accent
+            border: Color::DarkGray,
+            title: Color::White,
+            text: Color::Gray,
+            highlight: Color::Rgb(252, 152, 103), // Coral accent
+        }
+    }
+}
+
+impl Theme {
+    /// Loads `theme.toml` from the working directory, falling back to the
+    /// coral defaults for any key that is missing or the file entirely.
+    pub fn load() -> Self {
+        let mut theme = Theme::default();
+
+        let Ok(contents) = fs::read_to_string(THEME_FILE) else {
+            return theme;
+        };
+        let Ok(table) = contents.parse::<toml::Table>() else {
+            return theme;
+        };
+
+        if let Some(color) = lookup(&table, "active_tab") {
+            theme.active_tab = color;
+        }
+        if let Some(color) = lookup(&table, "inactive_tab") {
+            theme.inactive_tab = color;
+        }
+        if let Some(color) = lookup(&table, "selected_item") {
+            theme.selected_item = color;
+        }
+        if let Some(color) = lookup(&table, "border") {
+            theme.border = color;
+        }
+        if let Some(color) = lookup(&table, "title") {
+            theme.title = color;
+        }
+        if let Some(color) = lookup(&table, "text") {
+            theme.text = color;
+        }
+        if let Some(color) = lookup(&table, "highlight") {
+            theme.highlight = color;
+        }
+
+        theme
+    }
+}
+
+fn lookup(table: &toml::Table, key: &str) -> Option<Color> {
+    table.get(key)?.as_str().and_then(parse_color)
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    match value.strip_prefix('#') {
+        Some(hex) if hex.len() == 6 => {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            Some(Color::Rgb(r, g, b))
+        }
+        Some(_) => None,
+        None => named_color(value),
+    }
+}
+
+fn named_color(name: &str) -> Option<Color> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "white" => Color::White,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        _ => return None,
+    })
+}
@@ -0,0 +1,773 @@
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect, Alignment},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, BorderType, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+    Terminal, Frame,
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use std::io;
+use std::time::Duration;
+
+// Import types from main app
+use crate::{AppState, Folder, HistoryItem, FavoriteItem};
+use crate::fuzzy::{self, Field};
+use crate::html;
+use crate::theme::Theme;
+
+pub struct UI {
+    terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
+    theme: Theme,
+}
+
+impl UI {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = std::io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let terminal = Terminal::new(backend)?;
+
+        Ok(Self { terminal, theme: Theme::load() })
+    }
+
+    pub fn draw(&mut self, app_state: &AppState) -> Result<(), Box<dyn std::error::Error>> {
+        let theme = &self.theme;
+        self.terminal.draw(|f| {
+            Self::render_ui(f, app_state, theme);
+        })?;
+        Ok(())
+    }
+
+    fn render_ui(f: &mut Frame<CrosstermBackend<std::io::Stdout>>, app_state: &AppState, theme: &Theme) {
+        let size = f.size();
+
+        // Create a main layout with a header area and content area
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header with tabs
+                Constraint::Min(0),    // Content area
+            ])
+            .split(size);
+
+        // Render the tabs header
+        Self::render_tabs(f, app_state, theme, main_layout[0]);
+
+        // Split content into left panel and right panel
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30), // Left sidebar
+                Constraint::Percentage(70), // Right content
+            ])
+            .split(main_layout[1]);
+
+        // Render left panel content based on active tab
+        if app_state.show_favorites {
+            Self::render_favorites(f, app_state, theme, content_layout[0]);
+        } else if app_state.show_history {
+            Self::render_history(f, app_state, theme, content_layout[0]);
+        } else if app_state.folder_drilldown {
+            Self::render_articles(f, app_state, theme, content_layout[0]);
+        } else {
+            Self::render_folders(f, app_state, theme, content_layout[0]);
+        }
+
+        // Render right panel
+        Self::render_right_panel(f, app_state, theme, content_layout[1]);
+
+        // Render a subtle footer with keyboard shortcuts
+        Self::render_footer(f, theme, size);
+
+        if app_state.search_active {
+            Self::render_search_overlay(f, app_state, theme, size);
+        }
+    }
+
+    fn render_tabs(f: &mut Frame<CrosstermBackend<std::io::Stdout>>, app_state: &AppState, theme: &Theme, area: Rect) {
+        let tab_titles = vec!["Folders", "History", "Favorites"];
+        let selected_tab = if app_state.show_favorites {
+            2
+        } else if app_state.show_history {
+            1
+        } else {
+            0
+        };
+        
+        let tab_items: Vec<Spans> = tab_titles
+            .iter()
+            .map(|t| {
+                let (first, rest) = t.split_at(1);
+                Spans::from(vec![
+                    Span::styled(first, Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+                    Span::styled(rest, Style::default().fg(theme.text))
+                ])
+            })
+            .collect();
+
+        let tabs = Tabs::new(tab_items)
+            .select(selected_tab)
+            .style(Style::default().fg(theme.inactive_tab))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.active_tab)
+                    .add_modifier(Modifier::BOLD)
+            )
+            .divider(Span::raw(" | "));
+
+        f.render_widget(tabs, area);
+    }
+
+    fn render_favorites(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        let selected = app_state.favorite_sel.selected();
+        let favorite_items: Vec<ListItem> = app_state
+            .favorites
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let style = if Some(i) == selected {
+                    Style::default().fg(theme.selected_item).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+
+                // Create two-line list items with title and URL
+                let title = Spans::from(Span::styled(&item.title, style));
+                let url = Spans::from(Span::styled(
+                    format!("  {}", item.url),
+                    Style::default().fg(Color::DarkGray)
+                ));
+
+                ListItem::new(vec![title, url])
+                    .style(Style::default().bg(if Some(i) == selected {
+                        Color::Rgb(40, 40, 40) // Subtle highlight background
+                    } else {
+                        Color::Reset
+                    }))
+            })
+            .collect();
+
+        let favorites_list = List::new(favorite_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(Span::styled(" Favorites ", Style::default().fg(theme.title)))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("► ");
+
+        let mut state = app_state.favorite_sel.clone();
+        f.render_stateful_widget(favorites_list, area, &mut state);
+    }
+
+    fn render_history(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        let selected = app_state.history_sel.selected();
+        let history_items: Vec<ListItem> = app_state
+            .history
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let style = if Some(i) == selected {
+                    Style::default().fg(theme.selected_item).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+
+                // Create two-line list items
+                let title = Spans::from(Span::styled(&item.title, style));
+                let url = Spans::from(Span::styled(
+                    format!("  {}", item.url),
+                    Style::default().fg(Color::DarkGray)
+                ));
+
+                ListItem::new(vec![title, url])
+            })
+            .collect();
+
+        let history_list = List::new(history_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(Span::styled(" History ", Style::default().fg(theme.title)))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("► ");
+
+        let mut state = app_state.history_sel.clone();
+        f.render_stateful_widget(history_list, area, &mut state);
+    }
+
+    fn render_folders(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        let selected = app_state.folder_sel.selected();
+        let folder_items: Vec<ListItem> = app_state
+            .folders
+            .iter()
+            .enumerate()
+            .map(|(i, folder)| {
+                let style = if Some(i) == selected {
+                    Style::default().fg(theme.selected_item).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+
+                let label = if folder.refreshing {
+                    format!("{} (refreshing…)", folder.name)
+                } else {
+                    folder.name.clone()
+                };
+
+                ListItem::new(Span::styled(label, style))
+                    .style(Style::default().bg(if Some(i) == selected {
+                        Color::Rgb(40, 40, 40) // Subtle highlight background
+                    } else {
+                        Color::Reset
+                    }))
+            })
+            .collect();
+
+        let folder_list = List::new(folder_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(Span::styled(" Folders ", Style::default().fg(theme.title)))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("► ");
+
+        let mut state = app_state.folder_sel.clone();
+        f.render_stateful_widget(folder_list, area, &mut state);
+    }
+
+    /// Lists the articles of the folder currently selected in the Folders
+    /// tab, entered with Enter and left with Esc (see `handle_events`).
+    fn render_articles(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        let folder = app_state.folders.get(app_state.folder_sel.selected().unwrap_or(0));
+        let selected = app_state.article_sel.selected();
+        let article_items: Vec<ListItem> = folder
+            .map(|folder| folder.items.as_slice())
+            .unwrap_or(&[])
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let style = if Some(i) == selected {
+                    Style::default().fg(theme.selected_item).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                ListItem::new(Span::styled(item.title.clone(), style))
+            })
+            .collect();
+
+        let title = match folder {
+            Some(folder) => format!(" {} ", folder.name),
+            None => " Articles ".to_string(),
+        };
+
+        let articles_list = List::new(article_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(Span::styled(title, Style::default().fg(theme.title)))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("► ");
+
+        let mut state = app_state.article_sel.clone();
+        f.render_stateful_widget(articles_list, area, &mut state);
+    }
+
+    fn render_right_panel(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        // The right panel always mirrors whichever row is currently
+        // highlighted in the active left-hand list.
+        let text: Text = if app_state.show_favorites {
+            match app_state.favorites.get(app_state.favorite_sel.selected().unwrap_or(0)) {
+                Some(item) => Text::from(format!("Title: {}\nURL: {}", item.title, item.url)),
+                None => Text::from("No favorites yet"),
+            }
+        } else if app_state.show_history {
+            match app_state.history.get(app_state.history_sel.selected().unwrap_or(0)) {
+                Some(item) => Text::from(format!("Title: {}\nURL: {}", item.title, item.url)),
+                None => Text::from("No history yet"),
+            }
+        } else {
+            match app_state.folders.get(app_state.folder_sel.selected().unwrap_or(0)) {
+                Some(folder) => match folder.items.get(app_state.article_sel.selected().unwrap_or(0)) {
+                    Some(item) => {
+                        let mut spans = vec![
+                            Spans::from(Span::styled(
+                                format!("Title: {}", item.title),
+                                Style::default().fg(theme.title),
+                            )),
+                            Spans::from(Span::styled(
+                                format!("URL: {}", item.url),
+                                Style::default().fg(Color::DarkGray),
+                            )),
+                            Spans::from(""),
+                        ];
+                        let (body, _links) = html::render_html(&item.description);
+                        spans.extend(body);
+                        Text::from(spans)
+                    }
+                    None => Text::from(format!("Folder: {}\n(no articles fetched yet)", folder.name)),
+                },
+                None => Text::from("No item selected"),
+            }
+        };
+
+        let paragraph = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(Span::styled(" Details ", Style::default().fg(theme.title)))
+            )
+            .style(Style::default().fg(theme.text))
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(paragraph, area);
+    }
+
+    /// Draws the `/` fuzzy-search modal over the rest of the layout, showing
+    /// the query and the currently matching rows for whichever tab is active.
+    fn render_search_overlay(
+        f: &mut Frame<CrosstermBackend<std::io::Stdout>>,
+        app_state: &AppState,
+        theme: &Theme,
+        area: Rect,
+    ) {
+        let popup = centered_rect(60, 60, area);
+        f.render_widget(Clear, popup);
+
+        let popup_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(popup);
+
+        let input = Paragraph::new(format!("/{}", app_state.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.highlight))
+                .title(Span::styled(" Search ", Style::default().fg(theme.title))),
+        );
+        f.render_widget(input, popup_layout[0]);
+
+        let rows: Vec<ListItem> = Self::search_rows(app_state)
+            .into_iter()
+            .map(|(title, url, hit)| {
+                let title_spans = highlight(&title, hit.as_ref().filter(|h| h.field == Field::Title), theme);
+                let mut url_spans = vec![Span::raw("  ")];
+                url_spans.extend(highlight(&url, hit.as_ref().filter(|h| h.field == Field::Url), theme));
+                ListItem::new(vec![Spans::from(title_spans), Spans::from(url_spans)])
+            })
+            .collect();
+
+        let results = List::new(rows).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+                .title(Span::styled(" Results ", Style::default().fg(theme.title))),
+        );
+        f.render_widget(results, popup_layout[1]);
+    }
+
+    /// Rows from the currently active view (folder articles, history, or
+    /// favorites), scored and sorted against the in-progress query.
+    fn search_rows(app_state: &AppState) -> Vec<(String, String, Option<fuzzy::Hit>)> {
+        let candidates: Vec<(String, String)> = if app_state.show_favorites {
+            app_state
+                .favorites
+                .iter()
+                .map(|item| (item.title.clone(), item.url.clone()))
+                .collect()
+        } else if app_state.show_history {
+            app_state
+                .history
+                .iter()
+                .map(|item| (item.title.clone(), item.url.clone()))
+                .collect()
+        } else {
+            app_state
+                .folders
+                .iter()
+                .flat_map(|folder| folder.items.iter())
+                .map(|item| (item.title.clone(), item.url.clone()))
+                .collect()
+        };
+
+        if app_state.query.is_empty() {
+            return candidates
+                .into_iter()
+                .map(|(title, url)| (title, url, None))
+                .collect();
+        }
+
+        let mut scored: Vec<(String, String, fuzzy::Hit)> = candidates
+            .into_iter()
+            .filter_map(|(title, url)| {
+                fuzzy::best_match(&app_state.query, &title, &url).map(|hit| (title, url, hit))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.2.score.cmp(&a.2.score));
+        scored
+            .into_iter()
+            .map(|(title, url, hit)| (title, url, Some(hit)))
+            .collect()
+    }
+
+    fn render_footer(f: &mut Frame<CrosstermBackend<std::io::Stdout>>, theme: &Theme, area: Rect) {
+        let footer_area = Rect::new(
+            area.x,
+            area.height - 1,
+            area.width,
+            1
+        );
+        
+        let keys = vec![
+            Span::styled("q", Style::default().fg(theme.highlight)),
+            Span::raw(" quit • "),
+            Span::styled("↑/k", Style::default().fg(theme.highlight)),
+            Span::raw(" "),
+            Span::styled("↓/j", Style::default().fg(theme.highlight)),
+            Span::raw(" navigate • "),
+            Span::styled("f", Style::default().fg(theme.highlight)),
+            Span::raw(" add favorite • "),
+            Span::styled("r", Style::default().fg(theme.highlight)),
+            Span::raw(" refresh • "),
+            Span::styled("/", Style::default().fg(theme.highlight)),
+            Span::raw(" search • "),
+            Span::styled("enter", Style::default().fg(theme.highlight)),
+            Span::raw(" open folder • "),
+            Span::styled("esc", Style::default().fg(theme.highlight)),
+            Span::raw(" back • "),
+            Span::styled("tab", Style::default().fg(theme.highlight)),
+            Span::raw(" switch view"),
+        ];
+        
+        let footer = Paragraph::new(Spans::from(keys))
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+            
+        f.render_widget(footer, footer_area);
+    }
+
+    pub fn handle_events(&self, app_state: &mut AppState) -> Result<bool, Box<dyn std::error::Error>> {
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if app_state.search_active {
+                    Self::handle_search_key(key.code, app_state);
+                    return Ok(false);
+                }
+
+                match key.code {
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        if app_state.show_favorites {
+                            move_selection(&mut app_state.favorite_sel, app_state.favorites.len(), 1);
+                        } else if app_state.show_history {
+                            move_selection(&mut app_state.history_sel, app_state.history.len(), 1);
+                        } else if app_state.folder_drilldown {
+                            let len = current_folder_article_count(app_state);
+                            move_selection(&mut app_state.article_sel, len, 1);
+                        } else {
+                            move_selection(&mut app_state.folder_sel, app_state.folders.len(), 1);
+                            app_state.article_sel.select(Some(0));
+                        }
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        if app_state.show_favorites {
+                            move_selection(&mut app_state.favorite_sel, app_state.favorites.len(), -1);
+                        } else if app_state.show_history {
+                            move_selection(&mut app_state.history_sel, app_state.history.len(), -1);
+                        } else if app_state.folder_drilldown {
+                            let len = current_folder_article_count(app_state);
+                            move_selection(&mut app_state.article_sel, len, -1);
+                        } else {
+                            move_selection(&mut app_state.folder_sel, app_state.folders.len(), -1);
+                            app_state.article_sel.select(Some(0));
+                        }
+                    }
+                    KeyCode::Enter => {
+                        if app_state.folder_drilldown
+                            && !app_state.show_history
+                            && !app_state.show_favorites
+                        {
+                            // Already browsing this folder's articles: mark the
+                            // selected one as read. `main` picks it up via
+                            // `pending_history`, same handoff as `pending_favorite`.
+                            app_state.pending_history = true;
+                        } else if !app_state.show_history
+                            && !app_state.show_favorites
+                            && !app_state.folders.is_empty()
+                        {
+                            // Drill into the selected folder's articles.
+                            app_state.folder_drilldown = true;
+                        }
+                    }
+                    KeyCode::Esc => {
+                        // Back out of the article list to the folder list.
+                        if app_state.folder_drilldown {
+                            app_state.folder_drilldown = false;
+                        }
+                    }
+                    KeyCode::Tab => {
+                        // Cycle through views: Folders -> History -> Favorites -> Folders
+                        if !app_state.show_history && !app_state.show_favorites {
+                            // Currently in Folders, go to History
+                            app_state.show_history = true;
+                        } else if app_state.show_history {
+                            // Currently in History, go to Favorites
+                            app_state.show_history = false;
+                            app_state.show_favorites = true;
+                        } else {
+                            // Currently in Favorites, go to Folders
+                            app_state.show_favorites = false;
+                        }
+                    }
+                    KeyCode::Char('h') => {
+                        app_state.show_history = !app_state.show_history;
+                        app_state.show_favorites = false;
+                    }
+                    KeyCode::Char('F') => {
+                        app_state.show_favorites = !app_state.show_favorites;
+                        app_state.show_history = false;
+                    }
+                    KeyCode::Char('f') => {
+                        // Queue a favorite-add; `main` picks it up via handle_favorite_action.
+                        if app_state.show_history {
+                            app_state.pending_favorite = true;
+                        }
+                    }
+                    KeyCode::Char('r') => {
+                        app_state.force_refresh = true;
+                    }
+                    KeyCode::Char('/') => {
+                        app_state.search_active = true;
+                        app_state.query.clear();
+                    }
+                    KeyCode::Char('q') => return Ok(true), // Signal to quit
+                    _ => {}
+                }
+            }
+        }
+        Ok(false) // Continue running
+    }
+
+    /// Handles a keypress while the `/` search modal is open: edits the
+    /// query, or on Enter/Esc closes it (jumping to the top hit on Enter).
+    fn handle_search_key(code: KeyCode, app_state: &mut AppState) {
+        match code {
+            KeyCode::Esc => {
+                app_state.search_active = false;
+                app_state.query.clear();
+            }
+            KeyCode::Enter => {
+                app_state.search_active = false;
+                Self::jump_to_top_match(app_state);
+                app_state.query.clear();
+            }
+            KeyCode::Backspace => {
+                app_state.query.pop();
+            }
+            KeyCode::Char(c) => {
+                app_state.query.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the active view's selection to the best-scoring row for the
+    /// current query, if any row matches.
+    fn jump_to_top_match(app_state: &mut AppState) {
+        if app_state.query.is_empty() {
+            return;
+        }
+
+        if app_state.show_favorites {
+            let best = app_state
+                .favorites
+                .iter()
+                .enumerate()
+                .filter_map(|(i, item)| {
+                    fuzzy::best_match(&app_state.query, &item.title, &item.url).map(|hit| (i, hit.score))
+                })
+                .min_by_key(|(_, score)| std::cmp::Reverse(*score));
+            if let Some((idx, _)) = best {
+                app_state.favorite_sel.select(Some(idx));
+            }
+        } else if app_state.show_history {
+            let best = app_state
+                .history
+                .iter()
+                .enumerate()
+                .filter_map(|(i, item)| {
+                    fuzzy::best_match(&app_state.query, &item.title, &item.url).map(|hit| (i, hit.score))
+                })
+                .min_by_key(|(_, score)| std::cmp::Reverse(*score));
+            if let Some((idx, _)) = best {
+                app_state.history_sel.select(Some(idx));
+            }
+        } else {
+            let best = app_state
+                .folders
+                .iter()
+                .enumerate()
+                .flat_map(|(fi, folder)| folder.items.iter().enumerate().map(move |(ii, item)| (fi, ii, item)))
+                .filter_map(|(fi, ii, item)| {
+                    fuzzy::best_match(&app_state.query, &item.title, &item.url)
+                        .map(|hit| (fi, ii, hit.score))
+                })
+                .min_by_key(|(_, _, score)| std::cmp::Reverse(*score));
+            if let Some((fi, ii, _)) = best {
+                app_state.folder_sel.select(Some(fi));
+                app_state.article_sel.select(Some(ii));
+                app_state.folder_drilldown = true;
+            }
+        }
+    }
+
+    /// Consumes a pending "add to favorites" request queued by the `f` key,
+    /// telling `main` whether the currently highlighted history row should be
+    /// saved to favorites.
+    pub fn handle_favorite_action(&self, app_state: &mut AppState) -> Result<bool, Box<dyn std::error::Error>> {
+        if app_state.pending_favorite {
+            app_state.pending_favorite = false;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Consumes a pending "add to history" request queued by a second Enter
+    /// press in the article drilldown, telling `main` whether the currently
+    /// highlighted article should be recorded as read.
+    pub fn handle_history_action(&self, app_state: &mut AppState) -> Result<bool, Box<dyn std::error::Error>> {
+        if app_state.pending_history {
+            app_state.pending_history = false;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    pub fn cleanup(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        disable_raw_mode()?;
+        execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
+        self.terminal.show_cursor()?;
+        Ok(())
+    }
+}
+
+/// Number of articles in the folder currently selected in the Folders tab,
+/// used to bound `article_sel` movement.
+fn current_folder_article_count(app_state: &AppState) -> usize {
+    app_state
+        .folders
+        .get(app_state.folder_sel.selected().unwrap_or(0))
+        .map(|folder| folder.items.len())
+        .unwrap_or(0)
+}
+
+/// Moves a list selection by one row in the given direction, clamping to the
+/// list's bounds (or clearing it if the list is empty).
+fn move_selection(state: &mut ListState, len: usize, delta: i32) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+    let current = state.selected().unwrap_or(0).min(len - 1);
+    let next = if delta > 0 {
+        (current + 1).min(len - 1)
+    } else {
+        current.saturating_sub(1)
+    };
+    state.select(Some(next));
+}
+
+/// Splits `text` into spans, coloring the characters at `hit`'s matched
+/// positions in the theme's highlight color.
+fn highlight<'a>(text: &str, hit: Option<&fuzzy::Hit>, theme: &Theme) -> Vec<Span<'a>> {
+    let Some(hit) = hit else {
+        return vec![Span::raw(text.to_string())];
+    };
+
+    let matched: std::collections::HashSet<usize> = hit.positions.iter().copied().collect();
+    text.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if matched.contains(&i) {
+                Span::styled(
+                    c.to_string(),
+                    Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect()
+}
+
+/// A centered rectangle `percent_x` wide and `percent_y` tall within `area`,
+/// used to place the search modal over the rest of the layout.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}